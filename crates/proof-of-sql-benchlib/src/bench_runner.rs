@@ -1,34 +1,30 @@
-use crate::{export_tables_to_parquet, generate_random_columns, BenchmarkAccessor, QueryEntry};
-use ark_bn254::G1Affine as Bn254G1Affine;
-use ark_serialize::Validate;
+use crate::{
+    bench_scheme::BenchScheme,
+    bench_stats::{timing_stats, BenchSummary},
+    export_tables_to_parquet, generate_random_columns, load_table_from_parquet,
+    BenchmarkAccessor, HyperKzgBenchScheme, ParquetExportOptions, QueryEntry,
+};
 use bumpalo::Bump;
 use datafusion::config::ConfigOptions;
-use halo2curves::{
-    bn256::{Fq as Halo2Bn256Fq, G1Affine as Halo2Bn256G1Affine},
-    serde::SerdeObject,
-};
-use nova_snark::{
-    provider::{
-        bn256_grumpkin::bn256::Affine,
-        hyperkzg::{CommitmentEngine, CommitmentKey, EvaluationEngine, VerifierKey},
-    },
-    traits::{commitment::CommitmentEngineTrait, evaluation::EvaluationEngineTrait},
-};
 use proof_of_sql::{
-    base::{commitment::CommitmentEvaluationProof, database::TableRef},
-    proof_primitive::hyperkzg::{
-        deserialize_flat_compressed_hyperkzg_public_setup_from_reader,
-        nova_commitment_key_to_hyperkzg_public_setup, HyperKZGCommitmentEvaluationProof,
-        HyperKZGEngine,
+    base::{
+        commitment::CommitmentEvaluationProof,
+        database::{Column, OwnedColumn, OwnedTable, TableRef},
+        scalar::Scalar,
     },
+    proof_primitive::hyperkzg::HyperKZGCommitmentEvaluationProof,
     sql::proof::VerifiableQueryResult,
 };
 use proof_of_sql_planner::sql_to_proof_plans;
 use rand::{rngs::StdRng, SeedableRng};
 use sqlparser::dialect::GenericDialect;
 use std::{
-    fs::File,
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    thread,
     time::Instant,
 };
 
@@ -38,6 +34,16 @@ pub struct BenchOptions {
     pub table_size: usize,
     pub rand_seed: Option<u64>,
     pub parquet_dir: Option<PathBuf>,
+    pub parquet_export_options: ParquetExportOptions,
+    /// When set, tables are loaded from `<input_parquet_dir>/<query>/<table>.parquet`
+    /// (the layout produced by [`export_tables_to_parquet`]) instead of generated at random.
+    pub input_parquet_dir: Option<PathBuf>,
+    /// Number of leading iterations per query/plan whose timings are discarded, to let
+    /// the JIT/caches warm up before samples are recorded into [`BenchSummary`] stats.
+    pub warmup_iterations: usize,
+    /// Number of worker threads to fan independent prove/verify iterations out across.
+    /// `None` (the default) keeps the original strictly-sequential behavior.
+    pub parallelism: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -55,6 +61,7 @@ pub struct BenchResult {
 pub struct BenchRunOutput {
     pub results: Vec<BenchResult>,
     pub parquet_paths: Vec<PathBuf>,
+    pub summaries: Vec<BenchSummary>,
 }
 
 #[derive(Debug)]
@@ -74,6 +81,44 @@ impl From<std::io::Error> for BenchRunError {
     }
 }
 
+/// Converts one decoded [`OwnedColumn`] into the `Bump`-backed [`Column`] representation
+/// `BenchmarkAccessor` expects, mirroring what [`generate_random_columns`] produces.
+fn owned_column_to_column<'a, S: Scalar>(alloc: &'a Bump, column: OwnedColumn<S>) -> Column<'a, S> {
+    match column {
+        OwnedColumn::Boolean(values) => Column::Boolean(alloc.alloc_slice_copy(&values)),
+        OwnedColumn::TinyInt(values) => Column::TinyInt(alloc.alloc_slice_copy(&values)),
+        OwnedColumn::SmallInt(values) => Column::SmallInt(alloc.alloc_slice_copy(&values)),
+        OwnedColumn::Int(values) => Column::Int(alloc.alloc_slice_copy(&values)),
+        OwnedColumn::BigInt(values) => Column::BigInt(alloc.alloc_slice_copy(&values)),
+        OwnedColumn::Int128(values) => Column::Int128(alloc.alloc_slice_copy(&values)),
+        OwnedColumn::Scalar(values) => Column::Scalar(alloc.alloc_slice_copy(&values)),
+        OwnedColumn::Decimal75(precision, scale, values) => {
+            Column::Decimal75(precision, scale, alloc.alloc_slice_copy(&values))
+        }
+        OwnedColumn::VarChar(values) => {
+            let strings = alloc.alloc_slice_fill_iter(values.iter().map(|s| &*alloc.alloc_str(s)));
+            let scalars = alloc.alloc_slice_fill_iter(values.iter().map(|s| S::from(s.as_str())));
+            Column::VarChar((strings, scalars))
+        }
+        OwnedColumn::TimestampTZ(unit, tz, values) => {
+            Column::TimestampTZ(unit, tz, alloc.alloc_slice_copy(&values))
+        }
+    }
+}
+
+/// Converts a full [`OwnedTable`] (e.g. loaded from Parquet) into the column shape
+/// `BenchmarkAccessor::insert_table` expects.
+fn columns_from_owned_table<'a, S, I>(alloc: &'a Bump, owned_table: OwnedTable<S>) -> Vec<(I, Column<'a, S>)>
+where
+    S: Scalar,
+    OwnedTable<S>: IntoIterator<Item = (I, OwnedColumn<S>)>,
+{
+    owned_table
+        .into_iter()
+        .map(|(ident, column)| (ident, owned_column_to_column(alloc, column)))
+        .collect()
+}
+
 fn rng(options: &BenchOptions) -> StdRng {
     if let Some(seed) = options.rand_seed {
         StdRng::seed_from_u64(seed)
@@ -90,66 +135,83 @@ fn table_size_for_query(table_size: usize, query: &str) -> usize {
     }
 }
 
-/// Converts an Arkworks BN254 G1 Affine point to a Halo2 BN256 G1 Affine point.
-fn convert_to_halo2_bn256_g1_affine(point: &Bn254G1Affine) -> Halo2Bn256G1Affine {
-    if point.infinity {
-        return Halo2Bn256G1Affine::default();
+/// Runs `warmup_iterations` discarded iterations of `run_one`, then `iterations` recorded
+/// iterations, fanning the recorded iterations out across `parallelism` worker threads when
+/// set. `run_one` is called independently per iteration and must not mutate shared state, so
+/// only the (read-only) proving/verification work should live inside it; table setup happens
+/// once up front by the caller. Results are reassembled in iteration order regardless of which
+/// worker produced them, so output stays deterministic whether or not parallelism is enabled.
+/// Once any worker's `run_one` call fails, a shared stop flag halts every worker before its
+/// next iteration, so a known failure doesn't let other workers burn further prove/verify work.
+fn run_plan_samples<F>(
+    iterations: usize,
+    warmup_iterations: usize,
+    parallelism: Option<usize>,
+    run_one: F,
+) -> Result<Vec<(usize, u128, u128, usize)>, BenchRunError>
+where
+    F: Fn() -> Result<(u128, u128, usize), BenchRunError> + Sync,
+{
+    for _ in 0..warmup_iterations {
+        run_one()?;
     }
 
-    let x_bytes = bytemuck::cast::<[u64; 4], [u8; 32]>(point.x.0 .0);
-    let y_bytes = bytemuck::cast::<[u64; 4], [u8; 32]>(point.y.0 .0);
-
-    Halo2Bn256G1Affine {
-        x: Halo2Bn256Fq::from_raw_bytes_unchecked(&x_bytes),
-        y: Halo2Bn256Fq::from_raw_bytes_unchecked(&y_bytes),
+    let worker_count = parallelism.filter(|&n| n > 1).unwrap_or(1);
+    if worker_count <= 1 {
+        return (0..iterations)
+            .map(|i| run_one().map(|(generate_ns, verify_ns, n)| (i, generate_ns, verify_ns, n)))
+            .collect();
     }
-}
 
-fn load_hyperkzg_setup(
-    options: &BenchOptions,
-    ppot_path: Option<&Path>,
-) -> Result<(Vec<Bn254G1Affine>, VerifierKey<HyperKZGEngine>), BenchRunError> {
-    let (prover_setup, vk) = if let Some(ppot_file_path) = ppot_path {
-        let file = File::open(ppot_file_path)?;
-        let prover_setup =
-            deserialize_flat_compressed_hyperkzg_public_setup_from_reader(&file, Validate::Yes)
-                .map_err(|err| BenchRunError::Setup(err.to_string()))?;
-
-        let ck: CommitmentKey<HyperKZGEngine> = CommitmentKey::new(
-            prover_setup
-                .iter()
-                .map(convert_to_halo2_bn256_g1_affine)
-                .collect(),
-            Affine::default(),
-            halo2curves::bn256::G2Affine::default(),
-        );
-        let (_, vk) = EvaluationEngine::setup(&ck);
-
-        (prover_setup, vk)
-    } else {
-        let ck: CommitmentKey<HyperKZGEngine> =
-            CommitmentEngine::setup(b"bench", options.table_size);
-        let (_, vk) = EvaluationEngine::setup(&ck);
-        let prover_setup = nova_commitment_key_to_hyperkzg_public_setup(&ck);
-        (prover_setup, vk)
-    };
-
-    Ok((prover_setup, vk))
+    let (tx, rx) = mpsc::channel();
+    let failed = AtomicBool::new(false);
+    thread::scope(|scope| {
+        for worker in 0..worker_count {
+            let tx = tx.clone();
+            let run_one = &run_one;
+            let failed = &failed;
+            scope.spawn(move || {
+                let mut i = worker;
+                while i < iterations && !failed.load(Ordering::Relaxed) {
+                    let row = run_one().map(|(generate_ns, verify_ns, n)| (i, generate_ns, verify_ns, n));
+                    if row.is_err() {
+                        failed.store(true, Ordering::Relaxed);
+                    }
+                    if tx.send(row).is_err() {
+                        return;
+                    }
+                    i += worker_count;
+                }
+            });
+        }
+        drop(tx);
+    });
+
+    let mut rows = rx.into_iter().collect::<Result<Vec<_>, _>>()?;
+    rows.sort_by_key(|(i, ..)| *i);
+    Ok(rows)
 }
 
-pub fn run_hyperkzg_bench(
+/// Runs `queries` end-to-end (build/load tables, plan, prove, verify) under the commitment
+/// scheme `S`, so the same driver serves HyperKZG and any future scheme implementing
+/// [`BenchScheme`] without duplicating this loop per scheme.
+pub fn run_bench_with_scheme<C, S>(
     queries: &[QueryEntry],
     options: &BenchOptions,
     ppot_path: Option<&Path>,
-) -> Result<BenchRunOutput, BenchRunError> {
-    let (prover_setup, vk) = load_hyperkzg_setup(options, ppot_path)?;
-    let prover_setup_slice = prover_setup.as_slice();
-    let verifier_setup = &vk;
+) -> Result<BenchRunOutput, BenchRunError>
+where
+    C: CommitmentEvaluationProof,
+    S: BenchScheme<Proof = C>,
+{
+    let (prover_setup, verifier_setup) = S::load_setup(options, ppot_path)?;
+    let prover_setup_arg = S::prover_setup_arg(&prover_setup);
+    let verifier_setup_arg = S::verifier_setup_arg(&verifier_setup);
 
     let mut results = Vec::new();
+    let mut summaries = Vec::new();
     let mut parquet_paths = Vec::new();
-    let mut accessor: BenchmarkAccessor<'_, <HyperKZGCommitmentEvaluationProof as CommitmentEvaluationProof>::Commitment> =
-        BenchmarkAccessor::default();
+    let mut accessor: BenchmarkAccessor<'_, C::Commitment> = BenchmarkAccessor::default();
 
     let alloc = Bump::new();
     let mut rng = rng(options);
@@ -157,21 +219,37 @@ pub fn run_hyperkzg_bench(
     for (query, sql, tables, params) in queries {
         // Build tables
         for table in tables {
-            accessor.insert_table(
-                TableRef::from_names(None, table.name),
-                &generate_random_columns(
-                    &alloc,
-                    &mut rng,
-                    table.columns.as_slice(),
-                    table_size_for_query(options.table_size, query),
-                ),
-                &prover_setup_slice,
-            );
+            if let Some(input_parquet_dir) = &options.input_parquet_dir {
+                let owned_table = load_table_from_parquet(input_parquet_dir, query, table)
+                    .map_err(|err| BenchRunError::Parquet(format!("{err:?}")))?;
+                accessor.insert_table(
+                    TableRef::from_names(None, table.name),
+                    &columns_from_owned_table(&alloc, owned_table),
+                    &prover_setup_arg,
+                );
+            } else {
+                accessor.insert_table(
+                    TableRef::from_names(None, table.name),
+                    &generate_random_columns(
+                        &alloc,
+                        &mut rng,
+                        table.columns.as_slice(),
+                        table_size_for_query(options.table_size, query),
+                    ),
+                    &prover_setup_arg,
+                );
+            }
         }
 
         if let Some(parquet_dir) = &options.parquet_dir {
-            let outputs = export_tables_to_parquet(&accessor, tables, parquet_dir, query)
-                .map_err(|err| BenchRunError::Parquet(format!("{err:?}")))?;
+            let outputs = export_tables_to_parquet(
+                &accessor,
+                tables,
+                parquet_dir,
+                query,
+                &options.parquet_export_options,
+            )
+            .map_err(|err| BenchRunError::Parquet(format!("{err:?}")))?;
             parquet_paths.extend(outputs);
         }
 
@@ -182,39 +260,86 @@ pub fn run_hyperkzg_bench(
             .map_err(|err| BenchRunError::Planning(err.to_string()))?;
 
         for plan in plans {
-            for i in 0..options.iterations {
-                let time = Instant::now();
-                let res = VerifiableQueryResult::<HyperKZGCommitmentEvaluationProof>::new(
-                    &plan,
-                    &accessor,
-                    &prover_setup_slice,
-                    params,
-                )
-                .map_err(|err| BenchRunError::Proof(err.to_string()))?;
-                let generate_proof_elapsed = time.elapsed().as_millis();
-
-                let num_query_results = res.result.num_rows();
-
-                let time = Instant::now();
-                res.verify(&plan, &accessor, &verifier_setup, params)
-                    .map_err(|err| BenchRunError::Verify(err.to_string()))?;
-                let verify_elapsed = time.elapsed().as_millis();
+            let rows = run_plan_samples(
+                options.iterations,
+                options.warmup_iterations,
+                options.parallelism,
+                || {
+                    let time = Instant::now();
+                    let res = VerifiableQueryResult::<C>::new(
+                        &plan,
+                        &accessor,
+                        &prover_setup_arg,
+                        params,
+                    )
+                    .map_err(|err| BenchRunError::Proof(err.to_string()))?;
+                    let generate_proof_elapsed = time.elapsed();
+
+                    let num_query_results = res.result.num_rows();
+
+                    let time = Instant::now();
+                    res.verify(&plan, &accessor, &verifier_setup_arg, params)
+                        .map_err(|err| BenchRunError::Verify(err.to_string()))?;
+                    let verify_elapsed = time.elapsed();
+
+                    Ok((
+                        generate_proof_elapsed.as_nanos(),
+                        verify_elapsed.as_nanos(),
+                        num_query_results,
+                    ))
+                },
+            )?;
+
+            let mut generate_proof_samples_ns = Vec::with_capacity(rows.len());
+            let mut verify_proof_samples_ns = Vec::with_capacity(rows.len());
+
+            for (iteration, generate_proof_ns, verify_proof_ns, num_query_results) in rows {
+                generate_proof_samples_ns.push(generate_proof_ns);
+                verify_proof_samples_ns.push(verify_proof_ns);
 
                 results.push(BenchResult {
-                    commitment_scheme: "HyperKZG",
+                    commitment_scheme: S::NAME,
                     query: (*query).to_string(),
                     table_size: options.table_size,
-                    iteration: i,
-                    generate_proof_ms: generate_proof_elapsed,
-                    verify_proof_ms: verify_elapsed,
+                    iteration,
+                    generate_proof_ms: generate_proof_ns / 1_000_000,
+                    verify_proof_ms: verify_proof_ns / 1_000_000,
                     num_query_results,
                 });
             }
+
+            if let (Some(generate_proof), Some(verify_proof)) = (
+                (!generate_proof_samples_ns.is_empty()).then(|| timing_stats(&generate_proof_samples_ns)),
+                (!verify_proof_samples_ns.is_empty()).then(|| timing_stats(&verify_proof_samples_ns)),
+            ) {
+                summaries.push(BenchSummary {
+                    commitment_scheme: S::NAME,
+                    query: (*query).to_string(),
+                    table_size: options.table_size,
+                    sample_count: generate_proof_samples_ns.len(),
+                    generate_proof,
+                    verify_proof,
+                });
+            }
         }
     }
 
     Ok(BenchRunOutput {
         results,
         parquet_paths,
+        summaries,
     })
 }
+
+/// Runs `queries` under HyperKZG, the original (and still default) commitment scheme.
+/// Kept as a thin wrapper over [`run_bench_with_scheme`] for callers that don't need to name
+/// a scheme explicitly.
+pub fn run_hyperkzg_bench(
+    queries: &[QueryEntry],
+    options: &BenchOptions,
+    ppot_path: Option<&Path>,
+) -> Result<BenchRunOutput, BenchRunError> {
+    run_bench_with_scheme::<HyperKZGCommitmentEvaluationProof, HyperKzgBenchScheme>(
+        queries, options, ppot_path,
+    )
+}
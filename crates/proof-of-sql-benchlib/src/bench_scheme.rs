@@ -0,0 +1,227 @@
+use crate::{bench_runner::run_bench_with_scheme, BenchOptions, BenchRunError, BenchRunOutput, QueryEntry};
+use ark_bn254::G1Affine as Bn254G1Affine;
+use ark_serialize::Validate;
+use halo2curves::{
+    bn256::{Fq as Halo2Bn256Fq, G1Affine as Halo2Bn256G1Affine},
+    serde::SerdeObject,
+};
+use nova_snark::{
+    provider::{
+        bn256_grumpkin::bn256::Affine,
+        hyperkzg::{CommitmentEngine, CommitmentKey, EvaluationEngine, VerifierKey},
+    },
+    traits::{commitment::CommitmentEngineTrait, evaluation::EvaluationEngineTrait},
+};
+use proof_of_sql::{
+    base::commitment::CommitmentEvaluationProof,
+    proof_primitive::{
+        hyperkzg::{
+            deserialize_flat_compressed_hyperkzg_public_setup_from_reader,
+            nova_commitment_key_to_hyperkzg_public_setup, HyperKZGCommitmentEvaluationProof,
+            HyperKZGEngine,
+        },
+        inner_product::InnerProductProof,
+    },
+};
+use std::{fs::File, path::Path};
+
+/// A pluggable commitment/evaluation-proof scheme that [`crate::run_bench_with_scheme`] can
+/// drive, so one benchmark invocation can compare multiple schemes over the same
+/// generated/ingested tables. This crate implements it for HyperKZG and InnerProductProof only;
+/// there is no Dory implementation.
+pub trait BenchScheme {
+    /// The commitment/evaluation proof type this scheme drives `VerifiableQueryResult` with.
+    type Proof: CommitmentEvaluationProof;
+    /// Owned prover setup, kept alive for the duration of a run so `prover_setup_arg` can
+    /// hand out a borrowed `ProverPublicSetup` per call.
+    type ProverSetup;
+    /// Owned verifier setup, mirroring `ProverSetup`.
+    type VerifierSetup;
+
+    /// Name tagged onto every [`crate::BenchResult`]/[`crate::BenchSummary`] this scheme produces.
+    const NAME: &'static str;
+
+    /// Loads the prover/verifier setup: from a PPoT file when `ppot_path` is given, otherwise a
+    /// freshly generated key sized for `options.table_size`.
+    fn load_setup(
+        options: &BenchOptions,
+        ppot_path: Option<&Path>,
+    ) -> Result<(Self::ProverSetup, Self::VerifierSetup), BenchRunError>;
+
+    /// Borrows `setup` as the `ProverPublicSetup` `VerifiableQueryResult::new` expects.
+    fn prover_setup_arg(
+        setup: &Self::ProverSetup,
+    ) -> <Self::Proof as CommitmentEvaluationProof>::ProverPublicSetup<'_>;
+
+    /// Borrows `setup` as the `VerifierPublicSetup` `VerifiableQueryResult::verify` expects.
+    fn verifier_setup_arg(
+        setup: &Self::VerifierSetup,
+    ) -> <Self::Proof as CommitmentEvaluationProof>::VerifierPublicSetup<'_>;
+}
+
+/// Converts an Arkworks BN254 G1 Affine point to a Halo2 BN256 G1 Affine point.
+fn convert_to_halo2_bn256_g1_affine(point: &Bn254G1Affine) -> Halo2Bn256G1Affine {
+    if point.infinity {
+        return Halo2Bn256G1Affine::default();
+    }
+
+    let x_bytes = bytemuck::cast::<[u64; 4], [u8; 32]>(point.x.0 .0);
+    let y_bytes = bytemuck::cast::<[u64; 4], [u8; 32]>(point.y.0 .0);
+
+    Halo2Bn256G1Affine {
+        x: Halo2Bn256Fq::from_raw_bytes_unchecked(&x_bytes),
+        y: Halo2Bn256Fq::from_raw_bytes_unchecked(&y_bytes),
+    }
+}
+
+/// [`BenchScheme`] driving `HyperKZGCommitmentEvaluationProof`, the scheme
+/// `run_hyperkzg_bench` used to hard-code.
+pub struct HyperKzgBenchScheme;
+
+impl BenchScheme for HyperKzgBenchScheme {
+    type Proof = HyperKZGCommitmentEvaluationProof;
+    type ProverSetup = Vec<Bn254G1Affine>;
+    type VerifierSetup = VerifierKey<HyperKZGEngine>;
+
+    const NAME: &'static str = "HyperKZG";
+
+    fn load_setup(
+        options: &BenchOptions,
+        ppot_path: Option<&Path>,
+    ) -> Result<(Self::ProverSetup, Self::VerifierSetup), BenchRunError> {
+        let (prover_setup, vk) = if let Some(ppot_file_path) = ppot_path {
+            let file = File::open(ppot_file_path)?;
+            let prover_setup = deserialize_flat_compressed_hyperkzg_public_setup_from_reader(
+                &file,
+                Validate::Yes,
+            )
+            .map_err(|err| BenchRunError::Setup(err.to_string()))?;
+
+            let ck: CommitmentKey<HyperKZGEngine> = CommitmentKey::new(
+                prover_setup
+                    .iter()
+                    .map(convert_to_halo2_bn256_g1_affine)
+                    .collect(),
+                Affine::default(),
+                halo2curves::bn256::G2Affine::default(),
+            );
+            let (_, vk) = EvaluationEngine::setup(&ck);
+
+            (prover_setup, vk)
+        } else {
+            let ck: CommitmentKey<HyperKZGEngine> =
+                CommitmentEngine::setup(b"bench", options.table_size);
+            let (_, vk) = EvaluationEngine::setup(&ck);
+            let prover_setup = nova_commitment_key_to_hyperkzg_public_setup(&ck);
+            (prover_setup, vk)
+        };
+
+        Ok((prover_setup, vk))
+    }
+
+    fn prover_setup_arg(
+        setup: &Self::ProverSetup,
+    ) -> <Self::Proof as CommitmentEvaluationProof>::ProverPublicSetup<'_> {
+        setup.as_slice()
+    }
+
+    fn verifier_setup_arg(
+        setup: &Self::VerifierSetup,
+    ) -> <Self::Proof as CommitmentEvaluationProof>::VerifierPublicSetup<'_> {
+        setup
+    }
+}
+
+/// [`BenchScheme`] driving `InnerProductProof`, a curve25519-based scheme with no structured
+/// reference string: its generators are derived transparently at proving/verification time,
+/// so unlike [`HyperKzgBenchScheme`] it needs neither a PPoT file nor a generated commitment
+/// key.
+pub struct InnerProductBenchScheme;
+
+impl BenchScheme for InnerProductBenchScheme {
+    type Proof = InnerProductProof;
+    type ProverSetup = ();
+    type VerifierSetup = ();
+
+    const NAME: &'static str = "InnerProductProof";
+
+    fn load_setup(
+        _options: &BenchOptions,
+        _ppot_path: Option<&Path>,
+    ) -> Result<(Self::ProverSetup, Self::VerifierSetup), BenchRunError> {
+        Ok(((), ()))
+    }
+
+    fn prover_setup_arg(
+        _setup: &Self::ProverSetup,
+    ) -> <Self::Proof as CommitmentEvaluationProof>::ProverPublicSetup<'_> {
+    }
+
+    fn verifier_setup_arg(
+        _setup: &Self::VerifierSetup,
+    ) -> <Self::Proof as CommitmentEvaluationProof>::VerifierPublicSetup<'_> {
+    }
+}
+
+/// Identifies one of the commitment/evaluation schemes [`run_multi_scheme_bench`] can drive,
+/// so callers can select schemes by value (e.g. from a CLI flag) instead of naming types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchSchemeKind {
+    HyperKzg,
+    InnerProduct,
+}
+
+impl BenchSchemeKind {
+    /// Every scheme this crate currently knows how to benchmark.
+    pub const ALL: &'static [Self] = &[Self::HyperKzg, Self::InnerProduct];
+
+    /// The [`BenchScheme::NAME`] this kind drives.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::HyperKzg => HyperKzgBenchScheme::NAME,
+            Self::InnerProduct => InnerProductBenchScheme::NAME,
+        }
+    }
+
+    fn run(
+        self,
+        queries: &[QueryEntry],
+        options: &BenchOptions,
+        ppot_path: Option<&Path>,
+    ) -> Result<BenchRunOutput, BenchRunError> {
+        match self {
+            Self::HyperKzg => run_bench_with_scheme::<
+                HyperKZGCommitmentEvaluationProof,
+                HyperKzgBenchScheme,
+            >(queries, options, ppot_path),
+            // InnerProductProof has no structured setup, so there's no PPoT file to load.
+            Self::InnerProduct => {
+                run_bench_with_scheme::<InnerProductProof, InnerProductBenchScheme>(
+                    queries, options, None,
+                )
+            }
+        }
+    }
+}
+
+/// Runs `queries` under every scheme in `schemes`, so a single call can benchmark and directly
+/// compare multiple commitment schemes over the same tables: all generated tables are built
+/// from the same RNG seed (picked once up front when `options.rand_seed` is unset) and all
+/// ingested tables are read from the same files, so every scheme proves and verifies against
+/// identical data.
+pub fn run_multi_scheme_bench(
+    schemes: &[BenchSchemeKind],
+    queries: &[QueryEntry],
+    options: &BenchOptions,
+    ppot_path: Option<&Path>,
+) -> Result<Vec<BenchRunOutput>, BenchRunError> {
+    let mut options = options.clone();
+    if options.rand_seed.is_none() {
+        options.rand_seed = Some(rand::random());
+    }
+
+    schemes
+        .iter()
+        .map(|scheme| scheme.run(queries, &options, ppot_path))
+        .collect()
+}
@@ -0,0 +1,127 @@
+//! Statistical summaries over raw per-iteration timing samples, so reported benchmark
+//! numbers are robust to JIT/cache warm-up and scheduler jitter rather than a single
+//! millisecond-resolution reading.
+
+/// Summary statistics (in nanoseconds) over one batch of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimingStats {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+    /// Count of samples outside the Tukey fence `[Q1 - 1.5*IQR, Q3 + 1.5*IQR]`.
+    pub outlier_count: usize,
+}
+
+/// Per-(query, table_size) summary of a statistical benchmark run, covering both the
+/// proof generation and verification timings.
+#[derive(Debug, Clone)]
+pub struct BenchSummary {
+    pub commitment_scheme: &'static str,
+    pub query: String,
+    pub table_size: usize,
+    pub sample_count: usize,
+    pub generate_proof: TimingStats,
+    pub verify_proof: TimingStats,
+}
+
+/// Linearly-interpolated percentile of a *sorted* slice, `p` in `[0.0, 1.0]`.
+fn percentile(sorted: &[u128], p: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0] as f64;
+    }
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower] as f64
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] as f64 * (1.0 - frac) + sorted[upper] as f64 * frac
+    }
+}
+
+/// Computes min/median/mean/stddev and the Tukey-fence outlier count for `samples`.
+///
+/// Panics if `samples` is empty; callers should only invoke this once at least one
+/// timing sample has been collected.
+pub fn timing_stats(samples: &[u128]) -> TimingStats {
+    assert!(!samples.is_empty(), "timing_stats requires at least one sample");
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+
+    let min_ns = sorted[0];
+    let median_ns = percentile(&sorted, 0.5).round() as u128;
+
+    let mean_ns = sorted.iter().sum::<u128>() as f64 / sorted.len() as f64;
+    let variance = sorted
+        .iter()
+        .map(|&sample| {
+            let delta = sample as f64 - mean_ns;
+            delta * delta
+        })
+        .sum::<f64>()
+        / sorted.len() as f64;
+    let stddev_ns = variance.sqrt();
+
+    let q1 = percentile(&sorted, 0.25);
+    let q3 = percentile(&sorted, 0.75);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - 1.5 * iqr;
+    let upper_fence = q3 + 1.5 * iqr;
+    let outlier_count = sorted
+        .iter()
+        .filter(|&&sample| (sample as f64) < lower_fence || (sample as f64) > upper_fence)
+        .count();
+
+    TimingStats {
+        min_ns,
+        median_ns,
+        mean_ns,
+        stddev_ns,
+        outlier_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_of_single_sample_is_that_sample() {
+        assert_eq!(percentile(&[42], 0.5), 42.0);
+    }
+
+    #[test]
+    fn percentile_interpolates_between_ranks() {
+        let sorted = [10, 20, 30, 40];
+        assert_eq!(percentile(&sorted, 0.0), 10.0);
+        assert_eq!(percentile(&sorted, 1.0), 40.0);
+        assert_eq!(percentile(&sorted, 1.0 / 3.0), 20.0);
+        assert_eq!(percentile(&sorted, 0.5), 25.0);
+    }
+
+    #[test]
+    fn timing_stats_of_uniform_samples_has_zero_stddev_and_no_outliers() {
+        let stats = timing_stats(&[100, 100, 100, 100]);
+        assert_eq!(stats.min_ns, 100);
+        assert_eq!(stats.median_ns, 100);
+        assert_eq!(stats.mean_ns, 100.0);
+        assert_eq!(stats.stddev_ns, 0.0);
+        assert_eq!(stats.outlier_count, 0);
+    }
+
+    #[test]
+    fn timing_stats_flags_a_far_outlier() {
+        let stats = timing_stats(&[100, 101, 99, 100, 100_000]);
+        assert_eq!(stats.min_ns, 99);
+        assert_eq!(stats.outlier_count, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one sample")]
+    fn timing_stats_panics_on_empty_input() {
+        timing_stats(&[]);
+    }
+}
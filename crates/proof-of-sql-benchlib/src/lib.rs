@@ -2,15 +2,27 @@
 
 pub mod benchmark_accessor;
 pub mod bench_runner;
+pub mod bench_scheme;
+pub mod bench_stats;
 pub mod parquet_export;
+pub mod parquet_ingest;
 pub mod queries;
 pub mod random_util;
 
 pub use benchmark_accessor::BenchmarkAccessor;
 pub use bench_runner::{
     run_bench_with_scheme, run_hyperkzg_bench, BenchOptions, BenchResult, BenchRunError,
-    BenchRunOutput, BenchScheme, HyperKzgBenchScheme,
+    BenchRunOutput,
 };
-pub use parquet_export::{export_tables_to_parquet, ParquetExportError};
+pub use bench_scheme::{
+    run_multi_scheme_bench, BenchScheme, BenchSchemeKind, HyperKzgBenchScheme,
+    InnerProductBenchScheme,
+};
+pub use bench_stats::{BenchSummary, TimingStats};
+pub use parquet_export::{
+    export_tables_to_parquet, ParquetCompression, ParquetExportError, ParquetExportOptions,
+    ParquetWriterVersion,
+};
+pub use parquet_ingest::{load_table_from_parquet, ParquetIngestError};
 pub use queries::{all_queries, get_query, BaseEntry, QueryEntry, TableDefinition};
 pub use random_util::{generate_random_columns, OptionalRandBound};
@@ -8,8 +8,19 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use arrow::record_batch::RecordBatch;
-use parquet::arrow::ArrowWriter;
+use arrow::{
+    array::{Array, ArrayRef, StringArray, UInt32Array},
+    compute::{cast, take},
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    file::properties::{WriterProperties, WriterVersion},
+    schema::types::ColumnPath,
+};
+use std::{collections::{BTreeMap, HashSet}, sync::Arc};
 
 #[derive(Debug)]
 pub enum ParquetExportError {
@@ -20,6 +31,102 @@ pub enum ParquetExportError {
     MissingTable { table: String },
 }
 
+/// Compression codec applied to exported Parquet column chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParquetCompression {
+    None,
+    #[default]
+    Snappy,
+    Zstd,
+    Lz4,
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(value: ParquetCompression) -> Self {
+        match value {
+            ParquetCompression::None => Compression::UNCOMPRESSED,
+            ParquetCompression::Snappy => Compression::SNAPPY,
+            ParquetCompression::Zstd => Compression::ZSTD(ZstdLevel::default()),
+            ParquetCompression::Lz4 => Compression::LZ4,
+        }
+    }
+}
+
+/// Parquet format version to target when writing column chunks and pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParquetWriterVersion {
+    V1,
+    #[default]
+    V2,
+}
+
+impl From<ParquetWriterVersion> for WriterVersion {
+    fn from(value: ParquetWriterVersion) -> Self {
+        match value {
+            ParquetWriterVersion::V1 => WriterVersion::PARQUET_1_0,
+            ParquetWriterVersion::V2 => WriterVersion::PARQUET_2_0,
+        }
+    }
+}
+
+/// Configuration for [`export_tables_to_parquet`], mapping onto
+/// [`parquet::file::properties::WriterProperties`] so benchmark datasets can be written with
+/// realistic storage characteristics instead of library defaults.
+#[derive(Debug, Clone)]
+pub struct ParquetExportOptions {
+    pub compression: ParquetCompression,
+    pub writer_version: ParquetWriterVersion,
+    pub max_row_group_size: usize,
+    pub data_page_size: usize,
+    /// Default dictionary-encoding setting applied to every column.
+    pub dictionary_enabled: bool,
+    /// Per-column overrides of `dictionary_enabled`, keyed by column name.
+    pub column_dictionary_enabled: BTreeMap<String, bool>,
+    /// Default fraction of row count (0.0-1.0) for Arrow `DictionaryArray` encoding: a column
+    /// whose distinct-value count is at most `max(1, ceil(rows * fraction))` is encoded instead
+    /// of fully materialized; `0.0` disables it.
+    pub dictionary_cardinality_threshold: f64,
+    /// Per-column overrides of `dictionary_cardinality_threshold`, keyed by column name.
+    pub column_dictionary_cardinality_threshold: BTreeMap<String, f64>,
+    /// Tables written as a Hive-style partitioned directory tree instead of a single file,
+    /// keyed by table name, with the ordered list of partition column names to group by.
+    pub partition_columns: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for ParquetExportOptions {
+    fn default() -> Self {
+        Self {
+            compression: ParquetCompression::default(),
+            writer_version: ParquetWriterVersion::default(),
+            max_row_group_size: 1024 * 1024,
+            data_page_size: 1024 * 1024,
+            dictionary_enabled: true,
+            column_dictionary_enabled: BTreeMap::new(),
+            dictionary_cardinality_threshold: 0.01,
+            column_dictionary_cardinality_threshold: BTreeMap::new(),
+            partition_columns: BTreeMap::new(),
+        }
+    }
+}
+
+impl ParquetExportOptions {
+    fn writer_properties(&self) -> WriterProperties {
+        let mut builder = WriterProperties::builder()
+            .set_compression(self.compression.into())
+            .set_writer_version(self.writer_version.into())
+            .set_max_row_group_size(self.max_row_group_size)
+            .set_data_page_size_limit(self.data_page_size)
+            .set_dictionary_enabled(self.dictionary_enabled);
+
+        for (column, enabled) in &self.column_dictionary_enabled {
+            builder = builder
+                .set_column_dictionary_enabled(ColumnPath::from(column.as_str()), *enabled);
+        }
+
+        builder.build()
+    }
+}
+
 impl From<std::io::Error> for ParquetExportError {
     fn from(err: std::io::Error) -> Self {
         Self::Io(err)
@@ -44,7 +151,7 @@ impl From<OwnedTableError> for ParquetExportError {
     }
 }
 
-fn sanitize_component(input: &str) -> String {
+pub(crate) fn sanitize_component(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
     for ch in input.chars() {
         if ch.is_ascii_alphanumeric() || ch == '_' || ch == '-' {
@@ -60,13 +167,14 @@ fn sanitize_component(input: &str) -> String {
     }
 }
 
-fn table_ref_filename(table_ref: &TableRef) -> String {
+pub(crate) fn table_ref_filename(table_ref: &TableRef) -> String {
     sanitize_component(&table_ref.to_string()) + ".parquet"
 }
 
 fn record_batch_for_table<'a, C: Commitment>(
     accessor: &BenchmarkAccessor<'a, C>,
     table_ref: &TableRef,
+    options: &ParquetExportOptions,
 ) -> Result<RecordBatch, ParquetExportError> {
     let columns = accessor
         .table_columns(table_ref)
@@ -80,7 +188,167 @@ fn record_batch_for_table<'a, C: Commitment>(
             .map(|(ident, column)| (ident, OwnedColumn::from(&column))),
     )?;
 
-    Ok(RecordBatch::try_from(owned_table)?)
+    dictionary_encode_batch(RecordBatch::try_from(owned_table)?, options)
+}
+
+/// Columns of these Arrow types can be re-cast into `DictionaryArray<Int32Type>` without
+/// losing information, so they're the only ones considered for dictionary encoding.
+///
+/// `DataType::Boolean` is deliberately excluded: arrow-rs's `cast` kernel doesn't support
+/// casting a boolean array into a dictionary-encoded one, and a boolean column's cardinality
+/// (at most 2) would otherwise fall under the default threshold on virtually every export.
+fn is_dictionary_encodable(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+    )
+}
+
+/// Counts the distinct values in `column` by casting to `Utf8` and hashing the text
+/// representation, which keeps this generic across every dictionary-encodable Arrow type.
+fn distinct_value_count(column: &ArrayRef) -> Result<usize, ParquetExportError> {
+    let as_strings = cast(column, &DataType::Utf8)?;
+    let as_strings = as_strings
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("cast to Utf8 always yields a StringArray");
+    let distinct: HashSet<Option<&str>> = as_strings.iter().collect();
+    Ok(distinct.len())
+}
+
+/// Re-encodes low-cardinality columns of `batch` as Arrow `DictionaryArray`s, shrinking the
+/// materialized size of repetitive columns without changing their logical values.
+fn dictionary_encode_batch(
+    batch: RecordBatch,
+    options: &ParquetExportOptions,
+) -> Result<RecordBatch, ParquetExportError> {
+    let num_rows = batch.num_rows();
+    if num_rows == 0 {
+        return Ok(batch);
+    }
+
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+        let enabled = options
+            .column_dictionary_enabled
+            .get(field.name())
+            .copied()
+            .unwrap_or(options.dictionary_enabled);
+        let threshold_fraction = options
+            .column_dictionary_cardinality_threshold
+            .get(field.name())
+            .copied()
+            .unwrap_or(options.dictionary_cardinality_threshold);
+        let threshold = ((num_rows as f64) * threshold_fraction).ceil().max(1.0) as usize;
+
+        if enabled
+            && threshold_fraction > 0.0
+            && is_dictionary_encodable(field.data_type())
+            && distinct_value_count(column)? <= threshold
+        {
+            let dictionary_type =
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(field.data_type().clone()));
+            let dictionary_column = cast(column, &dictionary_type)?;
+            fields.push(Arc::new(Field::new(
+                field.name(),
+                dictionary_type,
+                field.is_nullable(),
+            )));
+            columns.push(dictionary_column);
+        } else {
+            fields.push(field.clone());
+            columns.push(column.clone());
+        }
+    }
+
+    Ok(RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?)
+}
+
+/// Groups `batch`'s row indices by the distinct tuple of values in `partition_columns`,
+/// returning `(sanitized directory components, row indices)` for each partition.
+fn partition_row_groups(
+    batch: &RecordBatch,
+    partition_columns: &[String],
+) -> Result<Vec<(Vec<String>, Vec<u32>)>, ParquetExportError> {
+    let key_columns = partition_columns
+        .iter()
+        .map(|name| {
+            let column = batch
+                .column_by_name(name)
+                .ok_or_else(|| {
+                    ParquetExportError::Arrow(arrow::error::ArrowError::SchemaError(format!(
+                        "partition column {name} not found"
+                    )))
+                })?;
+            let strings = cast(column, &DataType::Utf8)?;
+            Ok(strings)
+        })
+        .collect::<Result<Vec<ArrayRef>, ParquetExportError>>()?;
+
+    let mut row_indices_by_key: BTreeMap<Vec<String>, Vec<u32>> = BTreeMap::new();
+    for row in 0..batch.num_rows() {
+        let key = key_columns
+            .iter()
+            .map(|column| {
+                column
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .expect("cast to Utf8 always yields a StringArray")
+                    .value(row)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        row_indices_by_key.entry(key).or_default().push(row as u32);
+    }
+
+    Ok(row_indices_by_key
+        .into_iter()
+        .map(|(key, rows)| {
+            let directory_components = partition_columns
+                .iter()
+                .zip(&key)
+                .map(|(column, value)| {
+                    format!(
+                        "{}={}",
+                        sanitize_component(column),
+                        sanitize_component(value)
+                    )
+                })
+                .collect();
+            (directory_components, rows)
+        })
+        .collect())
+}
+
+fn take_batch(batch: &RecordBatch, row_indices: &[u32]) -> Result<RecordBatch, ParquetExportError> {
+    let indices = UInt32Array::from(row_indices.to_vec());
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| take(column, &indices, None))
+        .collect::<Result<Vec<ArrayRef>, _>>()?;
+    Ok(RecordBatch::try_new(batch.schema(), columns)?)
+}
+
+fn write_parquet_file(
+    path: &Path,
+    batch: &RecordBatch,
+    writer_properties: &WriterProperties,
+) -> Result<(), ParquetExportError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = File::create(path)?;
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(writer_properties.clone()))?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(())
 }
 
 pub fn export_tables_to_parquet<'a, C: Commitment>(
@@ -88,26 +356,142 @@ pub fn export_tables_to_parquet<'a, C: Commitment>(
     tables: &[TableDefinition],
     output_dir: impl AsRef<Path>,
     query_name: &str,
+    options: &ParquetExportOptions,
 ) -> Result<Vec<PathBuf>, ParquetExportError> {
     let query_dir = output_dir.as_ref().join(sanitize_component(query_name));
     fs::create_dir_all(&query_dir)?;
 
+    let writer_properties = options.writer_properties();
     let mut outputs = Vec::with_capacity(tables.len());
     for table in tables {
         let table_ref = TableRef::from_names(None, table.name);
-        let file_path = query_dir.join(table_ref_filename(&table_ref));
-        if file_path.exists() {
-            outputs.push(file_path);
-            continue;
-        }
 
-        let batch = record_batch_for_table(accessor, &table_ref)?;
-        let file = File::create(&file_path)?;
-        let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
-        writer.write(&batch)?;
-        writer.close()?;
-        outputs.push(file_path);
+        match options.partition_columns.get(table.name) {
+            Some(partition_columns) if !partition_columns.is_empty() => {
+                let table_dir = query_dir.join(sanitize_component(table.name));
+                let batch = record_batch_for_table(accessor, &table_ref, options)?;
+                for (directory_components, row_indices) in
+                    partition_row_groups(&batch, partition_columns)?
+                {
+                    let mut partition_dir = table_dir.clone();
+                    for component in &directory_components {
+                        partition_dir = partition_dir.join(component);
+                    }
+                    let file_path = partition_dir.join("part-0.parquet");
+                    if file_path.exists() {
+                        outputs.push(file_path);
+                        continue;
+                    }
+                    let partition_batch = take_batch(&batch, &row_indices)?;
+                    write_parquet_file(&file_path, &partition_batch, &writer_properties)?;
+                    outputs.push(file_path);
+                }
+            }
+            _ => {
+                let file_path = query_dir.join(table_ref_filename(&table_ref));
+                if file_path.exists() {
+                    outputs.push(file_path);
+                    continue;
+                }
+                let batch = record_batch_for_table(accessor, &table_ref, options)?;
+                write_parquet_file(&file_path, &batch, &writer_properties)?;
+                outputs.push(file_path);
+            }
+        }
     }
 
     Ok(outputs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::BooleanArray;
+
+    fn batch_with_bool_and_string_columns(num_rows: usize) -> RecordBatch {
+        let flags: ArrayRef = Arc::new(BooleanArray::from(vec![true, false].repeat(num_rows / 2)));
+        let low_cardinality: ArrayRef =
+            Arc::new(StringArray::from(vec!["x", "y"].repeat(num_rows / 2)));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("flag", DataType::Boolean, false),
+            Field::new("label", DataType::Utf8, false),
+        ]));
+        RecordBatch::try_new(schema, vec![flags, low_cardinality]).unwrap()
+    }
+
+    #[test]
+    fn dictionary_encode_batch_leaves_boolean_columns_untouched() {
+        let batch = batch_with_bool_and_string_columns(100);
+        let options = ParquetExportOptions::default();
+
+        let encoded = dictionary_encode_batch(batch, &options).expect("boolean column is not castable to a dictionary type");
+
+        assert_eq!(
+            encoded.schema().field(0).data_type(),
+            &DataType::Boolean,
+            "boolean columns must not be dictionary-encoded"
+        );
+    }
+
+    #[test]
+    fn dictionary_encode_batch_encodes_low_cardinality_string_columns() {
+        let batch = batch_with_bool_and_string_columns(1000);
+        let options = ParquetExportOptions::default();
+
+        let encoded = dictionary_encode_batch(batch, &options).unwrap();
+
+        assert!(matches!(
+            encoded.schema().field(1).data_type(),
+            DataType::Dictionary(_, _)
+        ));
+    }
+
+    #[test]
+    fn is_dictionary_encodable_excludes_boolean() {
+        assert!(!is_dictionary_encodable(&DataType::Boolean));
+        assert!(is_dictionary_encodable(&DataType::Utf8));
+    }
+
+    #[test]
+    fn dictionary_encode_batch_honors_per_column_disable_even_under_threshold() {
+        let batch = batch_with_bool_and_string_columns(1000);
+        let mut options = ParquetExportOptions::default();
+        options
+            .column_dictionary_enabled
+            .insert("label".to_string(), false);
+
+        let encoded = dictionary_encode_batch(batch, &options).unwrap();
+
+        assert_eq!(
+            encoded.schema().field(1).data_type(),
+            &DataType::Utf8,
+            "column_dictionary_enabled = false must be honored even for low-cardinality columns"
+        );
+    }
+
+    #[test]
+    fn dictionary_encode_batch_honors_global_disable() {
+        let batch = batch_with_bool_and_string_columns(100);
+        let mut options = ParquetExportOptions::default();
+        options.dictionary_enabled = false;
+
+        let encoded = dictionary_encode_batch(batch, &options).unwrap();
+
+        assert_eq!(encoded.schema().field(1).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn dictionary_encode_batch_honors_per_column_cardinality_threshold() {
+        let batch = batch_with_bool_and_string_columns(1000);
+        let mut options = ParquetExportOptions::default();
+        // Global threshold would normally select "label" (2 distinct values out of 1000 rows),
+        // but a per-column override of 0.0 should opt it out regardless.
+        options
+            .column_dictionary_cardinality_threshold
+            .insert("label".to_string(), 0.0);
+
+        let encoded = dictionary_encode_batch(batch, &options).unwrap();
+
+        assert_eq!(encoded.schema().field(1).data_type(), &DataType::Utf8);
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    parquet_export::{sanitize_component, table_ref_filename},
+    queries::TableDefinition,
+};
+use arrow::{
+    compute::{cast, concat_batches},
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use proof_of_sql::base::database::{ColumnType, OwnedTable, OwnedTableError, TableRef};
+use proof_of_sql::base::scalar::Scalar;
+use std::{
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+#[derive(Debug)]
+pub enum ParquetIngestError {
+    Io(std::io::Error),
+    Arrow(arrow::error::ArrowError),
+    Parquet(parquet::errors::ParquetError),
+    OwnedTable(OwnedTableError),
+    MissingFile {
+        path: PathBuf,
+    },
+    /// A column `table` expects to find by name simply isn't present in the Parquet file,
+    /// as opposed to [`Self::SchemaMismatch`] where the column exists with the wrong type.
+    MissingColumn {
+        table: String,
+        column: String,
+    },
+    SchemaMismatch {
+        table: String,
+        column: String,
+        expected: ColumnType,
+        found: ColumnType,
+    },
+}
+
+impl From<std::io::Error> for ParquetIngestError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<arrow::error::ArrowError> for ParquetIngestError {
+    fn from(err: arrow::error::ArrowError) -> Self {
+        Self::Arrow(err)
+    }
+}
+
+impl From<parquet::errors::ParquetError> for ParquetIngestError {
+    fn from(err: parquet::errors::ParquetError) -> Self {
+        Self::Parquet(err)
+    }
+}
+
+impl From<OwnedTableError> for ParquetIngestError {
+    fn from(err: OwnedTableError) -> Self {
+        Self::OwnedTable(err)
+    }
+}
+
+/// Expands any `DictionaryArray` columns (written by
+/// [`crate::export_tables_to_parquet`] for low-cardinality data) back into dense arrays, so
+/// dictionary encoding stays an on-disk storage optimization invisible to callers.
+fn decode_dictionaries(batch: RecordBatch) -> Result<RecordBatch, ParquetIngestError> {
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+        if let DataType::Dictionary(_, value_type) = field.data_type() {
+            columns.push(cast(column, value_type)?);
+            fields.push(Arc::new(Field::new(
+                field.name(),
+                (**value_type).clone(),
+                field.is_nullable(),
+            )));
+        } else {
+            fields.push(field.clone());
+            columns.push(column.clone());
+        }
+    }
+    Ok(RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?)
+}
+
+/// Recursively collects every `*.parquet` file under `dir`, in a deterministic (sorted) order,
+/// so a Hive-style partitioned export tree (`<table>/<col>=<value>/part-0.parquet`) reads back
+/// as the union of its partitions.
+fn collect_parquet_files(dir: &Path) -> Result<Vec<PathBuf>, ParquetIngestError> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(collect_parquet_files(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "parquet") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Reads a table written by [`crate::export_tables_to_parquet`] back into an [`OwnedTable`],
+/// validating that each column's type matches the expected [`ColumnType`]s in `table`.
+///
+/// Supports both layouts `export_tables_to_parquet` can produce: a single
+/// `<query>/<table>.parquet` file, or (when `partition_columns` was set for this table) the
+/// Hive-style `<query>/<table>/<col>=<value>/part-0.parquet` directory tree, whose partition
+/// files are concatenated back into one table.
+pub fn load_table_from_parquet<S: Scalar>(
+    input_dir: impl AsRef<Path>,
+    query_name: &str,
+    table: &TableDefinition,
+) -> Result<OwnedTable<S>, ParquetIngestError> {
+    let table_ref = TableRef::from_names(None, table.name);
+    let query_dir = input_dir.as_ref().join(sanitize_component(query_name));
+    let single_file_path = query_dir.join(table_ref_filename(&table_ref));
+    let partitioned_dir = query_dir.join(sanitize_component(table.name));
+
+    let file_paths = if single_file_path.exists() {
+        vec![single_file_path.clone()]
+    } else if partitioned_dir.is_dir() {
+        collect_parquet_files(&partitioned_dir)?
+    } else {
+        Vec::new()
+    };
+
+    if file_paths.is_empty() {
+        return Err(ParquetIngestError::MissingFile {
+            path: single_file_path,
+        });
+    }
+
+    let mut schema = None;
+    let mut batches = Vec::new();
+    for file_path in &file_paths {
+        let file = File::open(file_path)?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+        schema.get_or_insert_with(|| reader.schema());
+        for batch in reader {
+            batches.push(batch?);
+        }
+    }
+    let schema = schema.expect("file_paths is non-empty, so at least one file was read");
+    let batch = decode_dictionaries(concat_batches(&schema, &batches)?)?;
+
+    let owned_table = OwnedTable::<S>::try_from(batch)?;
+    validate_table_schema(&owned_table, table)?;
+
+    Ok(owned_table)
+}
+
+/// Checks that every column `table` declares is present in `owned_table` with the expected
+/// [`ColumnType`], reporting [`ParquetIngestError::MissingColumn`] and
+/// [`ParquetIngestError::SchemaMismatch`] distinctly so callers can tell "column absent" from
+/// "column present but wrong type" apart.
+fn validate_table_schema<S: Scalar>(
+    owned_table: &OwnedTable<S>,
+    table: &TableDefinition,
+) -> Result<(), ParquetIngestError> {
+    for entry in &table.columns {
+        let found = owned_table
+            .try_column_type(entry.name)
+            .ok_or_else(|| ParquetIngestError::MissingColumn {
+                table: table.name.to_string(),
+                column: entry.name.to_string(),
+            })?;
+        if found != entry.column_type {
+            return Err(ParquetIngestError::SchemaMismatch {
+                table: table.name.to_string(),
+                column: entry.name.to_string(),
+                expected: entry.column_type,
+                found,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::BaseEntry;
+    use arrow::array::{ArrayRef, Int64Array, StringArray};
+    use proof_of_sql::base::scalar::test_scalar::TestScalar;
+
+    fn sample_owned_table() -> OwnedTable<TestScalar> {
+        let ids: ArrayRef = Arc::new(Int64Array::from(vec![1, 2, 3]));
+        let names: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(schema, vec![ids, names]).unwrap();
+        OwnedTable::<TestScalar>::try_from(batch).unwrap()
+    }
+
+    fn table_def(columns: Vec<BaseEntry>) -> TableDefinition {
+        TableDefinition {
+            name: "t",
+            columns,
+        }
+    }
+
+    #[test]
+    fn collect_parquet_files_walks_partition_directories_in_order() {
+        let dir = std::env::temp_dir().join(format!(
+            "proof-of-sql-benchlib-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(dir.join("country=us")).unwrap();
+        fs::create_dir_all(dir.join("country=uk")).unwrap();
+        fs::write(dir.join("country=us").join("part-0.parquet"), b"").unwrap();
+        fs::write(dir.join("country=uk").join("part-0.parquet"), b"").unwrap();
+        fs::write(dir.join("country=uk").join("not-parquet.txt"), b"").unwrap();
+
+        let files = collect_parquet_files(&dir).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                dir.join("country=uk").join("part-0.parquet"),
+                dir.join("country=us").join("part-0.parquet"),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_table_schema_passes_when_types_match() {
+        let owned_table = sample_owned_table();
+        let table = table_def(vec![
+            BaseEntry {
+                name: "id",
+                column_type: ColumnType::BigInt,
+            },
+            BaseEntry {
+                name: "name",
+                column_type: ColumnType::VarChar,
+            },
+        ]);
+
+        assert!(validate_table_schema(&owned_table, &table).is_ok());
+    }
+
+    #[test]
+    fn validate_table_schema_reports_missing_column_distinctly_from_type_mismatch() {
+        let owned_table = sample_owned_table();
+        let table = table_def(vec![BaseEntry {
+            name: "does_not_exist",
+            column_type: ColumnType::BigInt,
+        }]);
+
+        match validate_table_schema(&owned_table, &table) {
+            Err(ParquetIngestError::MissingColumn { column, .. }) => {
+                assert_eq!(column, "does_not_exist");
+            }
+            other => panic!("expected MissingColumn, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn validate_table_schema_reports_type_mismatch_for_present_column() {
+        let owned_table = sample_owned_table();
+        let table = table_def(vec![BaseEntry {
+            name: "id",
+            column_type: ColumnType::VarChar,
+        }]);
+
+        match validate_table_schema(&owned_table, &table) {
+            Err(ParquetIngestError::SchemaMismatch {
+                expected, found, ..
+            }) => {
+                assert_eq!(expected, ColumnType::VarChar);
+                assert_eq!(found, ColumnType::BigInt);
+            }
+            other => panic!("expected SchemaMismatch, got {other:?}"),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use proof_of_sql_benchlib::{
-    get_query, run_bench_with_scheme, BenchOptions, HyperKzgBenchScheme,
+    get_query, run_bench_with_scheme, BenchOptions, HyperKzgBenchScheme, ParquetExportOptions,
 };
 use proof_of_sql::proof_primitive::hyperkzg::HyperKZGCommitmentEvaluationProof;
 use std::env;
@@ -25,8 +25,11 @@ fn benchmark_accessor_builds_filter_table() {
         iterations,
         table_size,
         rand_seed: Some(7),
-        parquet_output_dir: parquet_dir.clone().map(Into::into),
-        parquet_dir: None,
+        parquet_dir: parquet_dir.clone().map(Into::into),
+        parquet_export_options: ParquetExportOptions::default(),
+        input_parquet_dir: None,
+        warmup_iterations: 0,
+        parallelism: None,
     };
 
     if env::var("BENCH_PPOT_PATH").is_ok() && ppot_path.is_none() {
@@ -72,6 +75,17 @@ fn benchmark_accessor_builds_filter_table() {
         println!("Number of query results: {}", result.num_query_results);
     }
 
+    for summary in &output.summaries {
+        println!(
+            "summary: {} {} n={} prove_median_ns={} verify_median_ns={}",
+            summary.commitment_scheme,
+            summary.query,
+            summary.sample_count,
+            summary.generate_proof.median_ns,
+            summary.verify_proof.median_ns,
+        );
+    }
+
     assert!(!output.results.is_empty());
     assert!(output.results.len() >= iterations);
 }